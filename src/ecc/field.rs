@@ -1,8 +1,37 @@
 use anyhow::{anyhow, Result};
-use num::{BigUint, ToPrimitive};
-use std::marker::Copy;
+use num::{BigInt, BigUint, Zero};
+use std::cmp::Ordering;
+use std::fmt;
+use std::marker::PhantomData;
 use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign};
 
+/// The extended-Euclidean modular inverse of `num` mod `prime`, shared by
+/// `FieldElement<BigUint>::inverse` and `Fp<P>::inverse` so the two field
+/// types don't carry their own copy of the same algorithm.
+fn egcd_inverse(prime: &BigUint, num: &BigUint) -> BigUint {
+    let prime = BigInt::from(prime.clone());
+    let (mut old_r, mut r) = (prime.clone(), BigInt::from(num.clone()));
+    let (mut old_s, mut s) = (BigInt::zero(), BigInt::from(1_u32));
+
+    while r != BigInt::zero() {
+        let q = &old_r / &r;
+        let new_r = &old_r - &q * &r;
+        let new_s = &old_s - &q * &s;
+        old_r = r;
+        r = new_r;
+        old_s = s;
+        s = new_s;
+    }
+
+    if old_r != BigInt::from(1_u32) {
+        panic!("{} has no inverse mod {}", num, prime);
+    }
+
+    let inv = ((old_s % &prime) + &prime) % &prime;
+    inv.to_biguint()
+        .expect("inverse is reduced mod prime, so non-negative")
+}
+
 #[derive(PartialOrd, Ord, PartialEq, Clone, Eq, Debug, Hash)]
 pub struct FieldElement<T> {
     pub num: T,
@@ -32,6 +61,15 @@ impl FieldElement<BigUint> {
             prime: p,
         }
     }
+
+    /// Modular inverse via the extended Euclidean algorithm, in O(log prime)
+    /// instead of the O(prime) Fermat-exponentiation `num.pow(prime - 2)`.
+    pub fn inverse(&self) -> Self {
+        Self {
+            num: egcd_inverse(&self.prime, &self.num),
+            prime: self.prime.clone(),
+        }
+    }
 }
 
 impl AddAssign for FieldElement<BigUint> {
@@ -44,7 +82,7 @@ impl AddAssign for FieldElement<BigUint> {
         }
 
         *self = Self {
-            num: (&self.num + other.num).modpow(&BigUint::from(1 as u32), &self.prime),
+            num: (&self.num + other.num).modpow(&BigUint::from(1_u32), &self.prime),
             prime: other.prime,
         }
     }
@@ -61,7 +99,7 @@ impl Add for FieldElement<BigUint> {
             );
         }
         Self {
-            num: (self.num + other.num).modpow(&BigUint::from(1 as u32), &self.prime),
+            num: (self.num + other.num).modpow(&BigUint::from(1_u32), &self.prime),
             prime: self.prime,
         }
     }
@@ -69,9 +107,17 @@ impl Add for FieldElement<BigUint> {
 
 impl SubAssign for FieldElement<BigUint> {
     fn sub_assign(&mut self, other: Self) {
+        if self.prime != other.prime {
+            panic!(
+                "Expect {} == {}, found {} != {}",
+                self.prime, self.prime, self.prime, other.prime,
+            );
+        }
+
+        let prime = self.prime.clone();
         *self = Self {
-            num: (&self.num - other.num).modpow(&BigUint::from(1 as u32), &self.prime),
-            prime: other.prime,
+            num: (&self.num + &prime - other.num) % &prime,
+            prime,
         };
     }
 }
@@ -87,9 +133,12 @@ impl Sub for FieldElement<BigUint> {
             );
         }
 
+        // Add the modulus before subtracting so this never underflows, since
+        // `BigUint` has no negative values (`Fp::sub` does the same).
+        let prime = self.prime.clone();
         Self {
-            num: (self.num - other.num).modpow(&BigUint::from(1 as u32), &self.prime),
-            prime: self.prime,
+            num: (self.num + &prime - other.num) % &prime,
+            prime,
         }
     }
 }
@@ -97,7 +146,7 @@ impl Sub for FieldElement<BigUint> {
 impl MulAssign for FieldElement<BigUint> {
     fn mul_assign(&mut self, other: Self) {
         *self = Self {
-            num: (&self.num * other.num).modpow(&BigUint::from(1 as u32), &self.prime),
+            num: (&self.num * other.num).modpow(&BigUint::from(1_u32), &self.prime),
             prime: other.prime,
         };
     }
@@ -115,7 +164,7 @@ impl Mul for FieldElement<BigUint> {
         }
 
         Self {
-            num: (self.num * other.num).modpow(&BigUint::from(1 as u32), &self.prime),
+            num: (self.num * other.num).modpow(&BigUint::from(1_u32), &self.prime),
             prime: self.prime,
         }
     }
@@ -123,19 +172,13 @@ impl Mul for FieldElement<BigUint> {
 
 impl DivAssign for FieldElement<BigUint> {
     fn div_assign(&mut self, other: Self) {
-        let p = other.prime.clone();
         if self.prime != other.prime {
             panic!(
                 "Expect {} == {}, found {} != {}",
                 self.prime, self.prime, self.prime, other.prime,
             );
         }
-        let order = other.prime.to_u32().expect("fail to cast to u32");
-        let num = (&self.num * other.num.pow(order - 2)).modpow(&BigUint::from(1_u32), &p);
-        *self = Self {
-            num,
-            prime: other.prime,
-        }
+        *self = self.clone() * other.inverse();
     }
 }
 
@@ -143,18 +186,211 @@ impl Div for FieldElement<BigUint> {
     type Output = Self;
 
     fn div(self, other: Self) -> Self {
-        let p = other.prime.clone();
         if self.prime != other.prime {
             panic!(
                 "Expect {} == {}, found {} != {}",
                 self.prime, self.prime, self.prime, other.prime,
             );
         }
-        let order = other.prime.to_u32().expect("fail to cast to u32");
-        let num = (self.num * other.num.pow(order - 2)).modpow(&BigUint::from(1_u32), &p);
+        self * other.inverse()
+    }
+}
+
+/// A field element, abstracting over how its modulus is carried: stored
+/// at runtime (`FieldElement<BigUint>`) or encoded in the type (`Fp<P>`).
+/// Unifying behind this trait gives `Point` a single generic implementation
+/// instead of one hand-written copy per concrete coordinate type.
+pub trait Field:
+    Sized + Clone + Eq + fmt::Debug + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> + Div<Output = Self>
+{
+    /// The additive identity in the same field as `self`.
+    fn zero(&self) -> Self;
+    /// The multiplicative identity in the same field as `self`.
+    fn one(&self) -> Self;
+    fn is_zero(&self) -> bool;
+    fn inverse(&self) -> Self;
+    fn pow(self, exp: BigUint) -> Self;
+}
+
+impl Field for FieldElement<BigUint> {
+    fn zero(&self) -> Self {
         Self {
-            num,
-            prime: self.prime,
+            num: BigUint::zero(),
+            prime: self.prime.clone(),
+        }
+    }
+
+    fn one(&self) -> Self {
+        Self {
+            num: BigUint::from(1_u32),
+            prime: self.prime.clone(),
+        }
+    }
+
+    fn is_zero(&self) -> bool {
+        self.num.is_zero()
+    }
+
+    fn inverse(&self) -> Self {
+        FieldElement::inverse(self)
+    }
+
+    fn pow(self, exp: BigUint) -> Self {
+        FieldElement::pow(self, exp)
+    }
+}
+
+/// Marker for a prime modulus known at compile time, so `Fp<P>` can carry
+/// the modulus in its type instead of storing it on every value.
+pub trait PrimeFieldParams {
+    fn modulus() -> BigUint;
+}
+
+/// A field element whose modulus `P::modulus()` is fixed by the type `P`,
+/// so mismatched-field bugs become a type error instead of a runtime panic.
+pub struct Fp<P> {
+    pub num: BigUint,
+    _marker: PhantomData<P>,
+}
+
+impl<P: PrimeFieldParams> Fp<P> {
+    pub fn new(num: BigUint) -> Result<Self> {
+        let modulus = P::modulus();
+        if num >= modulus {
+            Err(anyhow!(
+                "Num {} not in field range O to {}",
+                num,
+                modulus - BigUint::from(1_u32)
+            ))
+        } else {
+            Ok(Self {
+                num,
+                _marker: PhantomData,
+            })
+        }
+    }
+
+    pub fn pow(self, exp: BigUint) -> Self {
+        let p = P::modulus();
+        let one = BigUint::from(1_u32);
+        let n = exp.modpow(&one.clone(), &(p.clone() - one));
+        Self {
+            num: self.num.modpow(&n, &p),
+            _marker: PhantomData,
+        }
+    }
+
+    pub fn inverse(&self) -> Self {
+        Self {
+            num: egcd_inverse(&P::modulus(), &self.num),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<P> Clone for Fp<P> {
+    fn clone(&self) -> Self {
+        Self {
+            num: self.num.clone(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<P> fmt::Debug for Fp<P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Fp").field("num", &self.num).finish()
+    }
+}
+
+impl<P> PartialEq for Fp<P> {
+    fn eq(&self, other: &Self) -> bool {
+        self.num == other.num
+    }
+}
+
+impl<P> Eq for Fp<P> {}
+
+impl<P> PartialOrd for Fp<P> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<P> Ord for Fp<P> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.num.cmp(&other.num)
+    }
+}
+
+impl<P: PrimeFieldParams> Add for Fp<P> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self {
+            num: (self.num + other.num) % P::modulus(),
+            _marker: PhantomData,
         }
     }
 }
+
+impl<P: PrimeFieldParams> Sub for Fp<P> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        let p = P::modulus();
+        Self {
+            num: (self.num + &p - other.num) % p,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<P: PrimeFieldParams> Mul for Fp<P> {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        Self {
+            num: (self.num * other.num) % P::modulus(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<P: PrimeFieldParams> Div for Fp<P> {
+    type Output = Self;
+
+    // Field division is multiplication by the modular inverse, not a typo for `*`.
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, other: Self) -> Self {
+        self * other.inverse()
+    }
+}
+
+impl<P: PrimeFieldParams> Field for Fp<P> {
+    fn zero(&self) -> Self {
+        Self {
+            num: BigUint::zero(),
+            _marker: PhantomData,
+        }
+    }
+
+    fn one(&self) -> Self {
+        Self {
+            num: BigUint::from(1_u32),
+            _marker: PhantomData,
+        }
+    }
+
+    fn is_zero(&self) -> bool {
+        self.num.is_zero()
+    }
+
+    fn inverse(&self) -> Self {
+        Fp::inverse(self)
+    }
+
+    fn pow(self, exp: BigUint) -> Self {
+        Fp::pow(self, exp)
+    }
+}
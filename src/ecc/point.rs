@@ -1,5 +1,8 @@
+use super::field::Field;
 use num::traits::pow::Pow;
+use num::traits::ToPrimitive;
 use num::BigInt;
+use num::BigUint;
 use num::Zero;
 use std::cmp::Ord;
 use std::marker::Copy;
@@ -32,6 +35,15 @@ impl Point<BigInt> {
     fn find_slope(p1: Point<BigInt>, p2: Point<BigInt>) -> Option<BigInt> {
         match (p1, p2) {
             (Self::Infinity(_, _), _) | (_, Self::Infinity(_, _)) => Some(BigInt::zero()),
+            (Self::OnCurve(x1, y1, a, ..), Self::OnCurve(x2, y2, ..)) if x1 == x2 && y1 == y2 => {
+                // Doubling a point: the chord formula degenerates since (x2 - x1) == 0,
+                // so use the tangent slope (3x1^2 + a) / (2y1) instead.
+                if y1 == BigInt::zero() {
+                    None
+                } else {
+                    Some((BigInt::from(3) * x1.pow(2_u32) + a) / (BigInt::from(2) * y1))
+                }
+            }
             (Self::OnCurve(x1, y1, ..), Self::OnCurve(x2, y2, ..)) => {
                 let x = x2 - x1;
                 if x > BigInt::from(0) {
@@ -43,6 +55,168 @@ impl Point<BigInt> {
             _ => None,
         }
     }
+
+    /// Scalar multiplication `coeff * self` via double-and-add.
+    ///
+    /// Kept as an inherent method (in addition to the `Mul<BigInt>` impl
+    /// below, which just delegates here) so callers don't need `use
+    /// std::ops::Mul` in scope to reach for it.
+    #[allow(clippy::should_implement_trait)]
+    pub fn mul(self, coeff: BigInt) -> Self {
+        if coeff < BigInt::zero() {
+            // (-k) * P == k * (-P), and the negation of (x, y) is (x, -y).
+            let negated = match self {
+                Self::OnCurve(x, y, a, b) => Self::OnCurve(x, -y, a, b),
+                other => other,
+            };
+            return negated.mul(-coeff);
+        }
+
+        let (a, b) = match &self {
+            Self::OnCurve(_, _, a, b) | Self::Infinity(a, b) => (a.clone(), b.clone()),
+            Self::OffCurve => panic!("cannot scale a point that is off the curve"),
+        };
+
+        let mut result = Self::Infinity(a, b);
+        let mut current = self;
+        let mut n = coeff;
+        let zero = BigInt::zero();
+        let two = BigInt::from(2);
+
+        while n > zero {
+            if &n % &two == BigInt::from(1) {
+                result = result + current.clone();
+            }
+            current = current.clone() + current;
+            n /= &two;
+        }
+
+        result
+    }
+}
+
+impl Mul<BigInt> for Point<BigInt> {
+    type Output = Self;
+
+    fn mul(self, coeff: BigInt) -> Self::Output {
+        Self::mul(self, coeff)
+    }
+}
+
+// Points over a prime field F_p, i.e. the coordinates used by real-world
+// curves such as secp256k1, rather than over the integers. Generic over any
+// `F: Field` so every field-backed coordinate type (the runtime-moduli
+// `FieldElement<BigUint>` as well as the type-level `Fp<P>`) shares this one
+// implementation instead of a hand-written copy per type.
+//
+// `Point<BigInt>` above is still a second, separately hand-written path:
+// `BigInt` isn't a `Field` (no modular inverse — it's the integers, not a
+// finite field), so it can't be folded into this impl. A fix to the
+// doubling/chord-slope math has to be applied to both `find_slope`s.
+impl<F: Field + Ord> Point<F> {
+    pub fn is_on_curve(x: &F, y: &F, a: &F, b: &F) -> bool {
+        y.clone() * y.clone() == x.clone() * x.clone() * x.clone() + a.clone() * x.clone() + b.clone()
+    }
+
+    pub fn identity(a: F, b: F) -> Self {
+        Self::Infinity(a, b)
+    }
+
+    pub fn new(x: F, y: F, a: F, b: F) -> Self {
+        if Self::is_on_curve(&x, &y, &a, &b) {
+            Self::OnCurve(x, y, a, b)
+        } else {
+            Self::OffCurve
+        }
+    }
+
+    fn find_slope(p1: Self, p2: Self) -> Option<F> {
+        match (p1, p2) {
+            (Self::Infinity(..), _) | (_, Self::Infinity(..)) => None,
+            (Self::OnCurve(x1, y1, a, ..), Self::OnCurve(x2, y2, ..)) if x1 == x2 && y1 == y2 => {
+                if y1.is_zero() {
+                    None
+                } else {
+                    // Tangent slope: (3x1^2 + a) / (2y1)
+                    let x1_sq = x1.clone() * x1.clone();
+                    let numerator = x1_sq.clone() + x1_sq.clone() + x1_sq + a;
+                    let denominator = y1.clone() + y1;
+                    Some(numerator / denominator)
+                }
+            }
+            (Self::OnCurve(x1, y1, ..), Self::OnCurve(x2, y2, ..)) if x1 != x2 => {
+                Some((y2 - y1) / (x2 - x1))
+            }
+            _ => None,
+        }
+    }
+
+    /// Scalar multiplication `coeff * self` via double-and-add, mirroring
+    /// `Point::<BigInt>::mul` but over a scalar `BigUint` so callers working
+    /// in a prime field (e.g. secp256k1) don't need a signed coefficient.
+    #[allow(clippy::should_implement_trait)]
+    pub fn mul(self, coeff: BigUint) -> Self {
+        let (a, b) = match &self {
+            Self::OnCurve(_, _, a, b) | Self::Infinity(a, b) => (a.clone(), b.clone()),
+            Self::OffCurve => panic!("cannot scale a point that is off the curve"),
+        };
+
+        let mut result = Self::Infinity(a, b);
+        let mut current = self;
+        let mut n = coeff;
+        let two = BigUint::from(2_u32);
+
+        while n > BigUint::zero() {
+            if &n % &two == BigUint::from(1_u32) {
+                result = result + current.clone();
+            }
+            current = current.clone() + current;
+            n /= &two;
+        }
+
+        result
+    }
+}
+
+impl<F: Field + Ord> Mul<BigUint> for Point<F> {
+    type Output = Self;
+
+    fn mul(self, coeff: BigUint) -> Self::Output {
+        Self::mul(self, coeff)
+    }
+}
+
+impl<F: Field + Ord> Add for Point<F> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self::Output {
+        if self == Self::OffCurve || other == Self::OffCurve {
+            panic!("Points {:?}, {:?} are not on the same curve", self, other);
+        }
+
+        match (self.clone(), other.clone()) {
+            (Self::Infinity(..), other) => other,
+            (me, Self::Infinity(..)) => me,
+            (Self::OnCurve(x1, y1, a, b), Self::OnCurve(x2, y2, ..)) => {
+                if x1 == x2 && y1 != y2 {
+                    return Self::Infinity(a, b);
+                }
+
+                if let Some(s) = Self::find_slope(self.clone(), other.clone()) {
+                    if self == other && y1.is_zero() {
+                        return Self::Infinity(a, b);
+                    }
+
+                    let x3 = s.clone() * s.clone() - x1.clone() - x2;
+                    let y3 = s * (x1 - x3.clone()) - y1;
+                    Self::new(x3, y3, a, b)
+                } else {
+                    Self::Infinity(a, b)
+                }
+            }
+            _ => Self::OffCurve,
+        }
+    }
 }
 
 impl Add for Point<BigInt> {
@@ -68,9 +242,9 @@ impl Add for Point<BigInt> {
                         return Self::Infinity(a, b);
                     }
 
-                    let x3 = BigInt::from(s.clone().pow(2_u32)) - x1.clone() - x2.clone();
+                    let x3 = s.clone().pow(2_u32) - x1.clone() - x2.clone();
                     let y3 = (s * (x1.clone() - x3.clone())) - y1;
-                    return Point::new(x3, y3, a, b);
+                    Self::new(x3, y3, a, b)
                 } else {
                     Point::Infinity(a, b)
                 }
@@ -79,3 +253,74 @@ impl Add for Point<BigInt> {
         }
     }
 }
+
+/// `sum(scalar_i * point_i)` via Pippenger's bucket method, far faster than
+/// one double-and-add per pair for large batches (e.g. batch signature
+/// verification).
+///
+/// Scalars are split into `ceil(max_bits / c)` windows of width `c` bits.
+/// Windows are folded in from most- to least-significant: each window buckets
+/// every point by its digit in that window, sums the buckets with the
+/// running-sum trick (`sum_i i*bucket_i`, accumulated top-down so each bucket
+/// is added the right number of times), and the running total is doubled `c`
+/// times before the next window is folded in.
+pub fn multiexp<F: Field + Ord>(pairs: &[(BigUint, Point<F>)]) -> Point<F> {
+    let (a, b) = match pairs.first() {
+        Some((_, Point::OnCurve(_, _, a, b))) | Some((_, Point::Infinity(a, b))) => {
+            (a.clone(), b.clone())
+        }
+        Some((_, Point::OffCurve)) => panic!("cannot scale a point that is off the curve"),
+        None => panic!("multiexp requires at least one (scalar, point) pair"),
+    };
+    let identity = Point::Infinity(a, b);
+
+    let c = window_width(pairs.len());
+    let max_bits = pairs.iter().map(|(s, _)| s.bits()).max().unwrap_or(1).max(1) as usize;
+    let num_windows = max_bits.div_ceil(c);
+    let num_buckets = (1_usize << c) - 1;
+
+    let mut result = identity.clone();
+    for window in (0..num_windows).rev() {
+        if window + 1 != num_windows {
+            for _ in 0..c {
+                result = result.clone() + result;
+            }
+        }
+
+        let mut buckets = vec![identity.clone(); num_buckets];
+        for (scalar, point) in pairs {
+            let digit = window_digit(scalar, window, c);
+            if digit > 0 {
+                buckets[digit - 1] = buckets[digit - 1].clone() + point.clone();
+            }
+        }
+
+        let mut running = identity.clone();
+        let mut window_sum = identity.clone();
+        for bucket in buckets.into_iter().rev() {
+            running = running + bucket;
+            window_sum = window_sum + running.clone();
+        }
+
+        result = result + window_sum;
+    }
+
+    result
+}
+
+/// Pippenger's suggested window width, `ln(num_pairs)`, floored at 1 bit.
+fn window_width(num_pairs: usize) -> usize {
+    if num_pairs < 3 {
+        1
+    } else {
+        (num_pairs as f64).ln().round() as usize
+    }
+}
+
+/// The `window`-th base-`2^c` digit of `scalar`.
+fn window_digit(scalar: &BigUint, window: usize, c: usize) -> usize {
+    let mask = (BigUint::from(1_u32) << c) - BigUint::from(1_u32);
+    ((scalar >> (window * c)) & mask)
+        .to_u64()
+        .expect("window digit fits in a u64") as usize
+}
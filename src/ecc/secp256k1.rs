@@ -0,0 +1,325 @@
+//! The Bitcoin curve: `y^2 = x^3 + 7` over F_p, plus ECDSA sign/verify.
+
+use super::field::{Fp, PrimeFieldParams};
+use super::point::Point;
+use anyhow::{anyhow, Result};
+use num::{BigUint, Zero};
+
+/// The secp256k1 base field modulus `p = 2^256 - 2^32 - 977`.
+pub struct FieldParams;
+
+impl PrimeFieldParams for FieldParams {
+    fn modulus() -> BigUint {
+        BigUint::parse_bytes(
+            b"FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEFFFFFC2F",
+            16,
+        )
+        .expect("valid hex literal")
+    }
+}
+
+/// The secp256k1 group order `n`, used for signature math instead of `p`.
+pub struct OrderParams;
+
+impl PrimeFieldParams for OrderParams {
+    fn modulus() -> BigUint {
+        BigUint::parse_bytes(
+            b"FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEBAAEDCE6AF48A03BBFD25E8CD0364141",
+            16,
+        )
+        .expect("valid hex literal")
+    }
+}
+
+/// A coordinate of a point on the curve, i.e. an element of F_p.
+pub type S256Field = Fp<FieldParams>;
+/// A scalar mod the group order `n`, i.e. the field signatures are computed in.
+pub type S256Order = Fp<OrderParams>;
+
+fn a() -> S256Field {
+    S256Field::new(BigUint::zero()).expect("0 is in range")
+}
+
+fn b() -> S256Field {
+    S256Field::new(BigUint::from(7_u32)).expect("7 is in range")
+}
+
+/// The group order `n`.
+pub fn order() -> BigUint {
+    OrderParams::modulus()
+}
+
+/// The standard base point `G`.
+pub fn generator() -> Point<S256Field> {
+    let x = BigUint::parse_bytes(
+        b"79BE667EF9DCBBAC55A06295CE870B07029BFCDB2DCE28D959F2815B16F81798",
+        16,
+    )
+    .expect("valid hex literal");
+    let y = BigUint::parse_bytes(
+        b"483ADA7726A3C4655DA4FBFC0E1108A8FD17B448A68554199C47D08FFB10D4B8",
+        16,
+    )
+    .expect("valid hex literal");
+    Point::<S256Field>::new(
+        S256Field::new(x).expect("Gx is in range"),
+        S256Field::new(y).expect("Gy is in range"),
+        a(),
+        b(),
+    )
+}
+
+/// FNV-1a, used below purely as a dependency-free way to get non-linear
+/// diffusion for [`nonce`] — not a cryptographic hash, and not suitable
+/// anywhere a real hash function is required.
+fn fnv1a(data: &[u8]) -> u64 {
+    const OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+    const PRIME: u64 = 0x0000_0100_0000_01b3;
+
+    let mut hash = OFFSET_BASIS;
+    for &byte in data {
+        hash ^= u64::from(byte);
+        hash = hash.wrapping_mul(PRIME);
+    }
+    hash
+}
+
+/// A deterministic nonce derived from the secret and message, reduced mod `n`.
+///
+/// This is a counter-mode KDF over `secret ‖ z ‖ attempt`, *not* RFC 6979.
+/// Crucially it is not an affine function of `secret` and `z` — a nonce such
+/// as `k = secret + z + attempt mod n` would be a textbook ECDSA break: two
+/// signatures from the same secret would expose a known linear relation
+/// between their nonces (`k1 - k2 = z1 - z2 mod n`), which alone is
+/// enough to solve for `secret` with no discrete-log computation at all. This
+/// crate is an educational toy implementation of the ECDSA *math*, not an
+/// audited signing scheme; don't use it to sign anything of real value.
+/// `attempt` lets `sign` step to a different nonce on the (astronomically
+/// unlikely) chance a given one yields `r == 0` or `s == 0`.
+fn nonce(secret: &BigUint, z: &BigUint, attempt: u32) -> BigUint {
+    let n = order();
+
+    let mut seed = secret.to_bytes_be();
+    seed.extend_from_slice(&z.to_bytes_be());
+    seed.extend_from_slice(&attempt.to_be_bytes());
+
+    let byte_len = n.to_bytes_be().len();
+    let mut expanded = Vec::with_capacity(byte_len + 8);
+    let mut counter: u64 = 0;
+    while expanded.len() < byte_len {
+        let mut block = seed.clone();
+        block.extend_from_slice(&counter.to_be_bytes());
+        expanded.extend_from_slice(&fnv1a(&block).to_be_bytes());
+        counter += 1;
+    }
+    expanded.truncate(byte_len);
+
+    let k = BigUint::from_bytes_be(&expanded) % &n;
+    if k.is_zero() {
+        BigUint::from(1_u32)
+    } else {
+        k
+    }
+}
+
+/// Sign `z` (a hash digest, reduced mod `n`) with `secret`, returning `(r, s)`.
+pub fn sign(secret: BigUint, z: BigUint) -> (BigUint, BigUint) {
+    let n = order();
+    let g = generator();
+
+    for attempt in 0.. {
+        let k = nonce(&secret, &z, attempt);
+        let r = match g.clone().mul(k.clone()) {
+            Point::OnCurve(x, ..) => x.num % &n,
+            _ => continue,
+        };
+        if r.is_zero() {
+            continue;
+        }
+
+        let k_inv = S256Order::new(k).expect("reduced mod n above").inverse();
+        let z_f = S256Order::new(z.clone() % &n).expect("reduced mod n above");
+        let r_f = S256Order::new(r.clone()).expect("reduced mod n above");
+        let secret_f = S256Order::new(secret.clone() % &n).expect("reduced mod n above");
+        let s = ((z_f + r_f * secret_f) * k_inv).num;
+        if s.is_zero() {
+            continue;
+        }
+
+        return (r, s);
+    }
+
+    unreachable!("nonce attempts are unbounded");
+}
+
+/// Verify that `sig` is a valid signature over `z` (reduced mod `n`) for `pubkey`.
+pub fn verify(pubkey: Point<S256Field>, z: BigUint, sig: (BigUint, BigUint)) -> bool {
+    let n = order();
+    let (r, s) = sig;
+    if r.is_zero() || r >= n || s.is_zero() || s >= n {
+        return false;
+    }
+
+    let s_inv = S256Order::new(s).expect("checked < n above").inverse();
+    let z_f = S256Order::new(z % &n).expect("reduced mod n above");
+    let r_f = S256Order::new(r.clone()).expect("checked < n above");
+    let u = (z_f * s_inv.clone()).num;
+    let v = (r_f * s_inv).num;
+
+    match generator().mul(u) + pubkey.mul(v) {
+        Point::OnCurve(x, ..) => x.num % &n == r,
+        _ => false,
+    }
+}
+
+fn to_32_bytes(n: &BigUint) -> [u8; 32] {
+    let digits = n.to_bytes_be();
+    let mut out = [0_u8; 32];
+    out[32 - digits.len()..].copy_from_slice(&digits);
+    out
+}
+
+/// `p ≡ 3 mod 4` for secp256k1, so `v^((p+1)/4) mod p` is a square root of `v`.
+fn sqrt(v: &S256Field) -> S256Field {
+    let exp = (FieldParams::modulus() + BigUint::from(1_u32)) / BigUint::from(4_u32);
+    v.clone().pow(exp)
+}
+
+impl Point<S256Field> {
+    /// SEC serialization: uncompressed is `0x04 || x || y`, compressed is
+    /// `0x02`/`0x03` (the parity of `y`) `|| x`, coordinates as 32 big-endian bytes.
+    pub fn to_sec(&self, compressed: bool) -> Vec<u8> {
+        let (x, y) = match self {
+            Self::OnCurve(x, y, ..) => (x, y),
+            _ => panic!("cannot serialize a point that is not on the curve"),
+        };
+
+        if compressed {
+            let prefix = if &y.num % BigUint::from(2_u32) == BigUint::zero() {
+                0x02
+            } else {
+                0x03
+            };
+            let mut out = vec![prefix];
+            out.extend_from_slice(&to_32_bytes(&x.num));
+            out
+        } else {
+            let mut out = vec![0x04];
+            out.extend_from_slice(&to_32_bytes(&x.num));
+            out.extend_from_slice(&to_32_bytes(&y.num));
+            out
+        }
+    }
+
+    /// Parse a SEC-encoded point, recovering `y` from `x` via [`sqrt`] when compressed.
+    ///
+    /// Errors (rather than panics) on truncated or malformed input, since
+    /// this is the entry point for bytes from the outside world.
+    pub fn parse_sec(bytes: &[u8]) -> Result<Self> {
+        let prefix = *bytes
+            .first()
+            .ok_or_else(|| anyhow!("SEC-encoded point is empty"))?;
+
+        match prefix {
+            0x04 => {
+                if bytes.len() < 65 {
+                    return Err(anyhow!(
+                        "uncompressed SEC point needs 65 bytes, got {}",
+                        bytes.len()
+                    ));
+                }
+                let x = S256Field::new(BigUint::from_bytes_be(&bytes[1..33]))?;
+                let y = S256Field::new(BigUint::from_bytes_be(&bytes[33..65]))?;
+                Ok(Self::new(x, y, a(), b()))
+            }
+            0x02 | 0x03 => {
+                if bytes.len() < 33 {
+                    return Err(anyhow!(
+                        "compressed SEC point needs 33 bytes, got {}",
+                        bytes.len()
+                    ));
+                }
+                let x = S256Field::new(BigUint::from_bytes_be(&bytes[1..33]))?;
+                let alpha = x.clone() * x.clone() * x.clone() + b();
+                let beta = sqrt(&alpha);
+                let beta_is_even = &beta.num % BigUint::from(2_u32) == BigUint::zero();
+                let wants_even = prefix == 0x02;
+                let y = if beta_is_even == wants_even {
+                    beta
+                } else {
+                    S256Field::new(FieldParams::modulus() - beta.num)?
+                };
+                Ok(Self::new(x, y, a(), b()))
+            }
+            other => Err(anyhow!("unknown SEC prefix byte {other:#x}")),
+        }
+    }
+}
+
+/// DER-encode an ECDSA `(r, s)` signature.
+///
+/// Only handles the short definite-length form, which always suffices here
+/// since `r` and `s` are at most 32 bytes (33 with a leading sign-pad byte).
+pub fn to_der(sig: &(BigUint, BigUint)) -> Vec<u8> {
+    let (r, s) = sig;
+    let r_bytes = der_integer(r);
+    let s_bytes = der_integer(s);
+
+    let mut body = vec![0x02, r_bytes.len() as u8];
+    body.extend_from_slice(&r_bytes);
+    body.push(0x02);
+    body.push(s_bytes.len() as u8);
+    body.extend_from_slice(&s_bytes);
+
+    let mut out = vec![0x30, body.len() as u8];
+    out.extend_from_slice(&body);
+    out
+}
+
+fn der_integer(n: &BigUint) -> Vec<u8> {
+    let mut bytes = n.to_bytes_be();
+    if bytes.is_empty() {
+        bytes.push(0);
+    }
+    if bytes[0] & 0x80 != 0 {
+        bytes.insert(0, 0);
+    }
+    bytes
+}
+
+/// Parse a DER-encoded `(r, s)` signature produced by [`to_der`].
+///
+/// Errors (rather than panics) on truncated or malformed input, since this
+/// is the entry point for bytes from the outside world.
+pub fn parse_der(bytes: &[u8]) -> Result<(BigUint, BigUint)> {
+    if bytes.len() < 2 || bytes[0] != 0x30 {
+        return Err(anyhow!("expected a DER sequence"));
+    }
+
+    let mut idx = 2;
+    let r = read_der_integer(bytes, &mut idx)?;
+    let s = read_der_integer(bytes, &mut idx)?;
+
+    Ok((r, s))
+}
+
+fn read_der_integer(bytes: &[u8], idx: &mut usize) -> Result<BigUint> {
+    if *idx + 2 > bytes.len() {
+        return Err(anyhow!("truncated DER integer"));
+    }
+    if bytes[*idx] != 0x02 {
+        return Err(anyhow!("expected a DER integer tag"));
+    }
+
+    let len = bytes[*idx + 1] as usize;
+    let start = *idx + 2;
+    let end = start
+        .checked_add(len)
+        .ok_or_else(|| anyhow!("DER integer length overflow"))?;
+    if end > bytes.len() {
+        return Err(anyhow!("truncated DER integer"));
+    }
+
+    *idx = end;
+    Ok(BigUint::from_bytes_be(&bytes[start..end]))
+}
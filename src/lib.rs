@@ -13,8 +13,8 @@ mod tests {
     fn create_field_element() {
         let (num, prime): (u32, u32) = (13, 13);
         match FieldElement::new(BigUint::from(num), BigUint::from(prime)) {
-            Err(_) => assert!(true, "Should return an error"),
-            _ => assert!(false),
+            Err(_) => (),
+            _ => panic!("Should return an error"),
         }
     }
 
@@ -94,7 +94,7 @@ mod tests {
         let p: usize = 31;
         let e: u32 = 2;
         let base: usize = 10;
-        let result = base.pow(e as u32).rem_euclid(p);
+        let result = base.pow(e).rem_euclid(p);
         let expected = FieldElement::new(BigUint::from(result), BigUint::from(p))?;
         let got = FieldElement::new(BigUint::from(base), BigUint::from(p))?.pow(BigUint::from(e));
 
@@ -161,15 +161,15 @@ mod tests {
             BigInt::from(5),
             BigInt::from(7),
         );
-        let p = Point::new(x.clone(), y.clone(), a.clone(), b.clone());
+        let p = Point::<BigInt>::new(x.clone(), y.clone(), a.clone(), b.clone());
         assert_eq!(p, Point::OnCurve(x, y, a, b));
     }
 
     #[test]
     fn point_addition_with_identity() {
         let (a, b) = (BigInt::from(5), BigInt::from(7));
-        let p1 = Point::new(BigInt::from(-1), BigInt::from(-1), a.clone(), b.clone());
-        let id = Point::identity(a, b);
+        let p1 = Point::<BigInt>::new(BigInt::from(-1), BigInt::from(-1), a.clone(), b.clone());
+        let id = Point::<BigInt>::identity(a, b);
         let s1 = p1.clone() + id.clone();
         let s2 = id + p1.clone();
         assert_eq!(s1, p1);
@@ -179,11 +179,11 @@ mod tests {
     #[test]
     fn point_additive_inverse() {
         let (a, b) = (BigInt::from(5), BigInt::from(7));
-        let p1 = Point::new(BigInt::from(-1), BigInt::from(-1), a.clone(), b.clone());
-        let p2 = Point::new(BigInt::from(-1), BigInt::from(1), a.clone(), b.clone());
+        let p1 = Point::<BigInt>::new(BigInt::from(-1), BigInt::from(-1), a.clone(), b.clone());
+        let p2 = Point::<BigInt>::new(BigInt::from(-1), BigInt::from(1), a.clone(), b.clone());
 
         let s = p1.clone() + p2.clone();
-        assert_eq!(s, Point::identity(a, b));
+        assert_eq!(s, Point::<BigInt>::identity(a, b));
     }
 
     #[test]
@@ -191,16 +191,26 @@ mod tests {
         // curve y^2 = x^3 + 5*x + 7
         // a = 5, b = 7
         let (a, b) = (5, 7);
-        let p = Point::new(
+        let p = Point::<BigInt>::new(
             BigInt::from(-1),
             BigInt::from(-1),
             BigInt::from(a),
             BigInt::from(b),
         );
 
+        // Doubling (-1, -1) via the tangent slope (3x^2 + a) / (2y) = -4
+        // lands on (18, 77), not at infinity.
         let mut res = p.clone() + p.clone();
-        assert_eq!(res, Point::Infinity(BigInt::from(a), BigInt::from(b)));
-        let p1 = Point::new(
+        assert_eq!(
+            res,
+            Point::OnCurve(
+                BigInt::from(18),
+                BigInt::from(77),
+                BigInt::from(a),
+                BigInt::from(b),
+            )
+        );
+        let p1 = Point::<BigInt>::new(
             BigInt::from(2),
             BigInt::from(5),
             BigInt::from(a),
@@ -209,4 +219,164 @@ mod tests {
         res = p1 + p;
         assert_eq!(res, Point::Infinity(BigInt::from(a), BigInt::from(b)));
     }
+
+    #[test]
+    fn test_point_addition_over_field_element() -> Result<()> {
+        // curve y^2 = x^3 + 7 over F_223 (a = 0, b = 7)
+        let p = BigUint::from(223_u32);
+        let (a, b) = (
+            FieldElement::new(BigUint::from(0_u32), p.clone())?,
+            FieldElement::new(BigUint::from(7_u32), p.clone())?,
+        );
+
+        let p1 = Point::<FieldElement<BigUint>>::new(
+            FieldElement::new(BigUint::from(192_u32), p.clone())?,
+            FieldElement::new(BigUint::from(105_u32), p.clone())?,
+            a.clone(),
+            b.clone(),
+        );
+        let p2 = Point::<FieldElement<BigUint>>::new(
+            FieldElement::new(BigUint::from(17_u32), p.clone())?,
+            FieldElement::new(BigUint::from(56_u32), p.clone())?,
+            a.clone(),
+            b.clone(),
+        );
+
+        let sum = p1.clone() + p2;
+        assert_eq!(
+            sum,
+            Point::OnCurve(
+                FieldElement::new(BigUint::from(170_u32), p.clone())?,
+                FieldElement::new(BigUint::from(142_u32), p.clone())?,
+                a.clone(),
+                b.clone(),
+            )
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_point_doubling_over_field_element() -> Result<()> {
+        // curve y^2 = x^3 + 7 over F_223 (a = 0, b = 7); exercises the
+        // tangent-slope branch of the generic `Point<F: Field>::find_slope`,
+        // not just the chord-slope branch covered above.
+        let p = BigUint::from(223_u32);
+        let (a, b) = (
+            FieldElement::new(BigUint::from(0_u32), p.clone())?,
+            FieldElement::new(BigUint::from(7_u32), p.clone())?,
+        );
+
+        let p1 = Point::<FieldElement<BigUint>>::new(
+            FieldElement::new(BigUint::from(47_u32), p.clone())?,
+            FieldElement::new(BigUint::from(71_u32), p.clone())?,
+            a.clone(),
+            b.clone(),
+        );
+
+        let dbl = p1.clone() + p1;
+        assert_eq!(
+            dbl,
+            Point::OnCurve(
+                FieldElement::new(BigUint::from(36_u32), p.clone())?,
+                FieldElement::new(BigUint::from(111_u32), p.clone())?,
+                a.clone(),
+                b.clone(),
+            )
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn secp256k1_sign_and_verify() {
+        use crate::ecc::secp256k1;
+
+        let secret = BigUint::from(12345_u32);
+        let pubkey = secp256k1::generator().mul(secret.clone());
+        let z = BigUint::from(999999_u32);
+
+        let sig = secp256k1::sign(secret, z.clone());
+        assert!(secp256k1::verify(pubkey.clone(), z.clone(), sig.clone()));
+
+        let wrong_z = BigUint::from(1_u32);
+        assert!(!secp256k1::verify(pubkey, wrong_z, sig));
+    }
+
+    #[test]
+    fn secp256k1_sec_roundtrip() -> Result<()> {
+        use crate::ecc::secp256k1;
+        use crate::ecc::Point;
+
+        let secret = BigUint::from(54321_u32);
+        let pubkey = secp256k1::generator().mul(secret);
+
+        let uncompressed = pubkey.to_sec(false);
+        assert_eq!(Point::parse_sec(&uncompressed)?, pubkey);
+
+        let compressed = pubkey.to_sec(true);
+        assert_eq!(compressed.len(), 33);
+        assert_eq!(Point::parse_sec(&compressed)?, pubkey);
+
+        Ok(())
+    }
+
+    #[test]
+    fn secp256k1_parse_sec_rejects_malformed_input() {
+        use crate::ecc::Point;
+
+        assert!(Point::parse_sec(&[]).is_err());
+        assert!(Point::parse_sec(&[0x04, 1, 2, 3]).is_err());
+        assert!(Point::parse_sec(&[0x02, 1, 2, 3]).is_err());
+        assert!(Point::parse_sec(&[0xff; 65]).is_err());
+    }
+
+    #[test]
+    fn secp256k1_der_roundtrip() -> Result<()> {
+        use crate::ecc::secp256k1;
+
+        let secret = BigUint::from(777_u32);
+        let z = BigUint::from(42_u32);
+        let sig = secp256k1::sign(secret, z);
+
+        let der = secp256k1::to_der(&sig);
+        assert_eq!(secp256k1::parse_der(&der)?, sig);
+
+        Ok(())
+    }
+
+    #[test]
+    fn secp256k1_parse_der_rejects_malformed_input() {
+        use crate::ecc::secp256k1;
+
+        assert!(secp256k1::parse_der(&[]).is_err());
+        assert!(secp256k1::parse_der(&[0x30]).is_err());
+        assert!(secp256k1::parse_der(&[0x30, 4, 0x02, 2, 1]).is_err());
+    }
+
+    #[test]
+    fn multiexp_matches_independent_scalar_mults() {
+        use crate::ecc::point::multiexp;
+        use crate::ecc::secp256k1;
+
+        let g = secp256k1::generator();
+        let p2 = g.clone().mul(BigUint::from(999_u32));
+        let p3 = g.clone().mul(BigUint::from(7777_u32));
+
+        let pairs = vec![
+            (BigUint::from(3_u32), g.clone()),
+            (BigUint::from(100_u32), p2.clone()),
+            (BigUint::from(12345_u32), p3.clone()),
+            (BigUint::from(999999_u32), g.clone()),
+        ];
+
+        let got = multiexp(&pairs);
+        let expected = pairs
+            .into_iter()
+            .map(|(s, p)| p.mul(s))
+            .reduce(|acc, p| acc + p)
+            .unwrap();
+
+        assert_eq!(got, expected);
+    }
 }